@@ -1,9 +1,11 @@
+use std::sync::{Arc, Mutex};
+
 use egui::{Button, Context, Layout, Ui};
 use egui_extras::{Column, TableBuilder};
 use sysinfo::{CpuExt, Pid, Process, ProcessExt, System, SystemExt};
 
 pub struct Application {
-    pub system: System,
+    pub system: Arc<Mutex<System>>,
     pub search: String,
     current_nav_item: NavItem,
 }
@@ -13,9 +15,10 @@ pub enum NavItem {
 }
 
 impl Application {
+    /// Builds the UI state around a system snapshot that is refreshed by the
+    /// background sampling thread (see `window::spawn_sampling_thread`).
     #[must_use]
-    pub fn new() -> Self {
-        let system = System::new_all();
+    pub fn new(system: Arc<Mutex<System>>) -> Self {
         let search = String::default();
 
         Self {
@@ -47,6 +50,8 @@ impl Application {
     }
 
     fn processes_view(&mut self, ui: &mut Ui) {
+        let system = self.system.lock().unwrap();
+
         ui.vertical_centered(|ui| {
             ui.add(
                 egui::TextEdit::singleline(&mut self.search)
@@ -76,7 +81,7 @@ impl Application {
                     });
                     header.col(|ui| {
                         ui.vertical(|ui| {
-                            ui.strong(format!("{:.1}%", self.system.global_cpu_info().cpu_usage()));
+                            ui.strong(format!("{:.1}%", system.global_cpu_info().cpu_usage()));
                             ui.label("CPU");
                         });
                     });
@@ -84,8 +89,7 @@ impl Application {
                         ui.vertical(|ui| {
                             ui.strong(format!(
                                 "{:.1}%",
-                                (self.system.used_memory() as f32
-                                    / self.system.total_memory() as f32)
+                                (system.used_memory() as f32 / system.total_memory() as f32)
                                     * 100.0
                             ));
                             ui.label("Memory");
@@ -96,8 +100,7 @@ impl Application {
                     });
                 })
                 .body(|mut body| {
-                    let mut processes = self
-                        .system
+                    let mut processes = system
                         .processes()
                         .iter()
                         .filter(|(pid, process)| {
@@ -150,6 +153,6 @@ impl Application {
 
 impl Default for Application {
     fn default() -> Self {
-        Self::new()
+        Self::new(Arc::new(Mutex::new(System::new_all())))
     }
 }
@@ -1,9 +1,11 @@
-use std::time::{Duration, Instant};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
 
 use egui::{FontDefinitions, Style, Visuals};
 use egui_wgpu_backend::{RenderPass, ScreenDescriptor};
 use egui_winit_platform::{Platform, PlatformDescriptor};
-use sysinfo::SystemExt;
+use sysinfo::{System, SystemExt};
 use wgpu::{Dx12Compiler, SurfaceError};
 use winit::{
     event::{ElementState, Event, KeyboardInput, VirtualKeyCode, WindowEvent},
@@ -13,6 +15,9 @@ use winit::{
 
 use crate::application::Application;
 
+/// How often the background thread refreshes its `System` snapshot.
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
 /// A custom event type for the winit app.
 enum EguiEvent {
     RequestRedraw,
@@ -32,6 +37,27 @@ impl epi::backend::RepaintSignal for ExampleRepaintSignal {
     }
 }
 
+/// Spawns the worker thread that owns data collection.
+///
+/// The thread refreshes its own `sysinfo::System` on `SAMPLE_INTERVAL`, publishes the result
+/// into `system` for the UI to read, and wakes the window via `repaint_signal` so rendering
+/// stays driven by new samples instead of a fixed polling timeout.
+fn spawn_sampling_thread(
+    system: Arc<Mutex<System>>,
+    repaint_signal: Arc<ExampleRepaintSignal>,
+) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+        let mut sample = System::new_all();
+        loop {
+            sample.refresh_all();
+            std::mem::swap(&mut *system.lock().unwrap(), &mut sample);
+            epi::backend::RepaintSignal::request_repaint(repaint_signal.as_ref());
+
+            thread::sleep(SAMPLE_INTERVAL);
+        }
+    })
+}
+
 struct State {
     surface: wgpu::Surface,
     device: wgpu::Device,
@@ -42,13 +68,11 @@ struct State {
     platform: Platform,
     egui_rpass: RenderPass,
     application: Application,
-
-    last_updated: Option<Instant>,
 }
 
 impl State {
     // Creating some of the wgpu types requires async code
-    async fn new(window: Window) -> Self {
+    async fn new(window: Window, system: Arc<Mutex<System>>) -> Self {
         let size = window.inner_size();
 
         // The instance is a handle to our GPU
@@ -120,9 +144,7 @@ impl State {
         // We use the egui_wgpu_backend crate as the render backend.
         let egui_rpass = RenderPass::new(&device, surface_format, 1);
 
-        let application = Application::new();
-
-        let last_updated = None;
+        let application = Application::new(system);
 
         Self {
             surface,
@@ -134,7 +156,6 @@ impl State {
             platform,
             egui_rpass,
             application,
-            last_updated,
         }
     }
 
@@ -156,12 +177,6 @@ impl State {
         false
     }
 
-    fn update(&mut self) {
-        self.application.system.refresh_all();
-
-        self.last_updated = Some(Instant::now());
-    }
-
     fn render(&mut self) -> anyhow::Result<(), SurfaceError> {
         let output = self.surface.get_current_texture()?;
         let view = output
@@ -227,7 +242,11 @@ pub async fn run() -> anyhow::Result<()> {
         .with_resizable(true)
         .build(&event_loop)?;
 
-    let mut state = State::new(window).await;
+    let system = Arc::new(Mutex::new(System::new_all()));
+    let repaint_signal = Arc::new(ExampleRepaintSignal(Mutex::new(event_loop.create_proxy())));
+    spawn_sampling_thread(system.clone(), repaint_signal);
+
+    let mut state = State::new(window, system).await;
 
     if let Some(theme) = state.window().theme() {
         set_visuals_from_theme(theme, &state);
@@ -236,7 +255,7 @@ pub async fn run() -> anyhow::Result<()> {
     event_loop.run(move |event, _, control_flow| {
         // Pass the winit events to the platform integration.
         state.platform.handle_event(&event);
-        control_flow.set_wait_timeout(Duration::from_secs(1));
+        control_flow.set_wait(); // redraws are driven by window events and sampling ticks
 
         match event {
             Event::WindowEvent {
@@ -267,14 +286,6 @@ pub async fn run() -> anyhow::Result<()> {
                 }
             },
             Event::RedrawRequested(window_id) if window_id == state.window().id() => {
-                if let Some(last_updated) = state.last_updated {
-                    if last_updated.elapsed() >= Duration::from_secs(1) {
-                        state.update();
-                    }
-                } else {
-                    state.update();
-                }
-
                 match state.render() {
                     Ok(_) => {}
                     // Reconfigure the surface if lost
@@ -285,14 +296,10 @@ pub async fn run() -> anyhow::Result<()> {
                     Err(e) => eprintln!("{e:?}"),
                 }
             }
-            Event::MainEventsCleared => {
-                // RedrawRequested will only trigger once, unless we manually
-                // request it.
-                if let Some(last_updated) = state.last_updated {
-                    if last_updated.elapsed() >= Duration::from_secs(1) {
-                        state.window().request_redraw();
-                    }
-                }
+            // The sampling thread requests a repaint through the proxy whenever it
+            // publishes a fresh snapshot, so we redraw on its cadence rather than ours.
+            Event::UserEvent(EguiEvent::RequestRedraw) => {
+                state.window().request_redraw();
             }
             _ => {}
         }